@@ -0,0 +1,152 @@
+// Same persistent stack as `third.rs`, but built on `Arc` instead of `Rc`.
+// `Rc`'s reference count is not atomic, so `List<T>` is neither `Send` nor
+// `Sync` and can never cross a thread boundary. `Arc` is `Rc`'s atomic
+// counterpart: same shared-ownership, cheap-clone story, but the count can
+// be bumped safely from multiple threads at once, so `ArcList<T>` itself
+// can be `Send`/`Sync` (whenever `T` is) and handed to `thread::spawn`.
+
+use std::sync::Arc;
+
+type Link<T> = Option<Arc<Node<T>>>;
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+pub struct ArcList<T> {
+    head: Link<T>,
+}
+
+impl<T> ArcList<T> {
+    pub fn new() -> Self {
+        ArcList { head: None }
+    }
+
+    pub fn prepend(&self, elem: T) -> ArcList<T> {
+        ArcList {
+            head: Some(Arc::new(Node {
+                elem,
+                next: self.head.clone(),
+            })),
+        }
+    }
+
+    pub fn tail(&self) -> ArcList<T> {
+        let tail = self.head.as_ref().and_then(|arc_node| arc_node.next.clone());
+        ArcList { head: tail }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|arc_node| &arc_node.elem)
+    }
+}
+
+impl<T> Clone for ArcList<T> {
+    // Cloning only bumps the `Arc` refcount of the head node; it never
+    // touches `T`, so this doesn't need (and mustn't require) `T: Clone`.
+    fn clone(&self) -> Self {
+        ArcList {
+            head: self.head.clone(),
+        }
+    }
+}
+
+impl<T> Drop for ArcList<T> {
+    // Same bottom-stopping loop as `third::List`'s `Drop`: only the owner
+    // that observes a refcount of one is allowed to keep unwinding the
+    // chain, so a tail still shared by another list is left untouched.
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            if let Ok(mut node) = Arc::try_unwrap(node) {
+                head = node.next.take();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<T> ArcList<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArcList;
+
+    #[test]
+    fn basics() {
+        let list = ArcList::new();
+        assert_eq!(list.head(), None);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.head(), Some(&3));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&2));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&1));
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+        // Make sure empty tail works
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list = ArcList::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ArcList<i32>>();
+    }
+
+    #[test]
+    fn shared_tail_across_threads() {
+        use std::thread;
+
+        let list = ArcList::new().prepend(1).prepend(2).prepend(3);
+
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let list = list.clone();
+                thread::spawn(move || list.head().copied())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Some(3));
+        }
+    }
+}
@@ -18,14 +18,18 @@
 // Rc is like Box, but we can only get
 // shared references of the internal values :(
 
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
 use std::rc::Rc;
 
 type Link<T> = Option<Rc<Node<T>>>;
+#[derive(Debug)]
 struct Node<T> {
     elem: T,
     next: Link<T>,
 }
 
+#[derive(Debug)]
 pub struct List<T> {
     head: Link<T>,
 }
@@ -61,6 +65,40 @@ impl<T> List<T> {
     }
 }
 
+impl<T: Clone> List<T> {
+    // The data behind an `Rc` is shared, so we can't move it out to build
+    // the prefix list: we have to `clone` each `elem` into a freshly
+    // allocated `Node`. The suffix, on the other hand, can just grab the
+    // `Rc` sitting at position `n` and bump its refcount, no allocation
+    // and no cloning of `T` at all, so it keeps sharing memory with the
+    // list it came from.
+    pub fn split_off(&self, n: usize) -> (List<T>, List<T>) {
+        // `n` is allowed to run past the end of the list (see
+        // `split_off_past_the_end` below), so it can't be trusted as a
+        // capacity hint: `Vec::with_capacity(n)` would try to preallocate
+        // for a caller-supplied `n` regardless of how many nodes actually
+        // exist.
+        let mut prefix = Vec::new();
+        let mut rest = self.head.clone();
+        for _ in 0..n {
+            match rest {
+                Some(node) => {
+                    prefix.push(node.elem.clone());
+                    rest = node.next.clone();
+                }
+                None => break,
+            }
+        }
+
+        let mut prefix_list = List::new();
+        for elem in prefix.into_iter().rev() {
+            prefix_list = prefix_list.prepend(elem);
+        }
+
+        (prefix_list, List { head: rest })
+    }
+}
+
 impl<T> Drop for List<T> {
     // We can't iterate over "next" and replace them with None 
     // like we did with our non-rc version: that involves 
@@ -104,6 +142,94 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+pub struct IntoIter<T> {
+    next: Link<T>,
+}
+
+impl<T: Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(mut self) -> IntoIter<T> {
+        IntoIter {
+            next: self.head.take(),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.next.take().map(|node| {
+            // We don't own `node`'s contents outright, someone else might
+            // still be holding the same `Rc`. So we try to unwrap it to
+            // reclaim `elem` for free, and only fall back to cloning if
+            // the node is part of a tail some other list still shares.
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => {
+                    self.next = node.next.take();
+                    node.elem
+                }
+                Err(node) => {
+                    self.next = node.next.clone();
+                    node.elem.clone()
+                }
+            }
+        })
+    }
+}
+
+impl<T> Drop for IntoIter<T> {
+    // Same bottom-stopping loop as `List<T>`'s `Drop` (the compiler-derived
+    // recursive drop glue for the remaining `Rc<Node<T>>` chain would blow
+    // the stack on a long, privately-owned list if we left this to the
+    // default glue): only the owner that observes a refcount of one keeps
+    // unwinding, so a tail still shared elsewhere is left untouched.
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(node) = next {
+            if let Ok(mut node) = Rc::try_unwrap(node) {
+                next = node.next.take();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    // Two lists are equal if they have the same elements in the same
+    // order, regardless of whether they actually share any nodes.
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    // We only have `prepend`, which builds back-to-front, so we collect
+    // into a `Vec` first and prepend in reverse to keep the iteration
+    // order matching insertion order.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        let mut list = List::new();
+        for elem in items.into_iter().rev() {
+            list = list.prepend(elem);
+        }
+        list
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
@@ -139,4 +265,117 @@ mod test {
         assert_eq!(iter.next(), Some(&2));
         assert_eq!(iter.next(), Some(&1));
     }
+
+    #[test]
+    fn into_iter() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_with_shared_tail() {
+        // list2 shares list1's tail, so consuming list1 can't move `2` or
+        // `1` out: it has to clone them, while `3` is still moved for free.
+        let list1 = List::new().prepend(1).prepend(2).prepend(3);
+        let list2 = list1.tail();
+
+        let mut iter = list1.into_iter();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list2.head(), Some(&2));
+    }
+
+    #[test]
+    fn into_iter_partial_drain_does_not_overflow_stack() {
+        // A long, privately-owned list: if `IntoIter` were dropped with no
+        // `Drop` impl of its own, the compiler-derived drop glue for the
+        // remaining `Rc<Node<T>>` chain would recurse node-by-node and blow
+        // the stack, the same failure mode `List<T>`'s own `Drop` exists to
+        // prevent.
+        let list = (0..200_000).fold(List::new(), |list, i| list.prepend(i));
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(199_999));
+        drop(iter);
+    }
+
+    #[test]
+    fn split_off() {
+        let list = List::new().prepend(1).prepend(2).prepend(3).prepend(4);
+
+        let (prefix, suffix) = list.split_off(2);
+        assert_eq!(prefix.iter().collect::<Vec<_>>(), vec![&4, &3]);
+        assert_eq!(suffix.iter().collect::<Vec<_>>(), vec![&2, &1]);
+
+        // The suffix shares nodes with the original list, not copies.
+        assert!(std::ptr::eq(
+            suffix.head().unwrap(),
+            list.tail().tail().head().unwrap()
+        ));
+    }
+
+    #[test]
+    fn split_off_past_the_end() {
+        let list = List::new().prepend(1).prepend(2);
+
+        let (prefix, suffix) = list.split_off(10);
+        assert_eq!(prefix.iter().collect::<Vec<_>>(), vec![&2, &1]);
+        assert_eq!(suffix.head(), None);
+    }
+
+    #[test]
+    fn split_off_with_huge_n_does_not_preallocate() {
+        let list = List::new().prepend(1).prepend(2);
+
+        let (prefix, suffix) = list.split_off(usize::MAX / 2);
+        assert_eq!(prefix.iter().collect::<Vec<_>>(), vec![&2, &1]);
+        assert_eq!(suffix.head(), None);
+    }
+
+    #[test]
+    fn eq() {
+        let list1 = List::new().prepend(1).prepend(2).prepend(3);
+        let list2 = List::new().prepend(1).prepend(2).prepend(3);
+        let list3 = List::new().prepend(1).prepend(2);
+
+        assert_eq!(list1, list2);
+        assert_ne!(list1, list3);
+    }
+
+    #[test]
+    fn from_iter() {
+        let list: List<i32> = vec![3, 2, 1].into_iter().collect();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::Hasher;
+
+        fn hash_of<T: std::hash::Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let list1 = List::new().prepend(1).prepend(2).prepend(3);
+        let list2: List<i32> = vec![3, 2, 1].into_iter().collect();
+
+        assert_eq!(list1, list2);
+        assert_eq!(hash_of(&list1), hash_of(&list2));
+    }
 }